@@ -0,0 +1,90 @@
+use super::{Backend, Key};
+use crate::color::Rgb;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::style::{Color, ResetColor, SetBackgroundColor, SetForegroundColor};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use std::io::{stdout, Stdout, Write};
+
+/// Terminal backend built on top of `crossterm`, portable to Windows as well as Unix
+pub struct CrosstermBackend {
+    out: Stdout,
+}
+
+impl Backend for CrosstermBackend {
+    fn new() -> Self {
+        terminal::enable_raw_mode().unwrap();
+        let mut out = stdout();
+        execute!(out, EnterAlternateScreen).unwrap();
+        Self { out }
+    }
+
+    fn size() -> (u16, u16) {
+        terminal::size().unwrap()
+    }
+
+    fn hide_cursor(&mut self) {
+        // queued, not executed: `execute!` flushes after every single call, which would cost a
+        // syscall per dirty cell since `Screen::print` calls these once per changed cell; rely
+        // on the explicit `flush()` at the end of the frame instead, like `TermionBackend` does
+        queue!(self.out, Hide).unwrap();
+    }
+
+    fn show_cursor(&mut self) {
+        queue!(self.out, Show).unwrap();
+    }
+
+    fn set_fg(&mut self, color: Rgb) {
+        queue!(self.out, SetForegroundColor(Color::Rgb { r: color.0, g: color.1, b: color.2 })).unwrap();
+    }
+
+    fn set_bg(&mut self, color: Rgb) {
+        queue!(self.out, SetBackgroundColor(Color::Rgb { r: color.0, g: color.1, b: color.2 })).unwrap();
+    }
+
+    fn move_to(&mut self, x: u16, y: u16) {
+        // crossterm uses 0-based coordinates, the rest of the codebase uses termion's 1-based ones
+        queue!(self.out, MoveTo(x.saturating_sub(1), y.saturating_sub(1))).unwrap();
+    }
+
+    fn clear(&mut self) {
+        queue!(self.out, Clear(ClearType::All)).unwrap();
+    }
+
+    fn print(&mut self, c: char) {
+        write!(self.out, "{c}").unwrap();
+    }
+
+    fn flush(&mut self) {
+        self.out.flush().unwrap();
+    }
+
+    fn read_key() -> Option<Key> {
+        loop {
+            // some backends (notably Windows) report both a Press and a Release event per
+            // physical keypress; only react to the former or every key would register twice
+            if let Ok(Event::Key(key_event)) = event::read() {
+                if key_event.kind != KeyEventKind::Press {
+                    continue;
+                }
+                return Some(match key_event.code {
+                    KeyCode::Char(c) => Key::Char(c),
+                    KeyCode::Esc => Key::Esc,
+                    _ => Key::Other,
+                });
+            }
+        }
+    }
+}
+
+/// leave the alternate screen and restore the terminal mode once the backend is dropped
+impl Drop for CrosstermBackend {
+    fn drop(&mut self) {
+        // reset the color left by the last set_fg/set_bg call before leaving the alternate
+        // screen; some terminals don't scope SGR state to the alternate buffer, so skipping this
+        // would leave the user's shell prompt rendered in the last rain color
+        let _ = execute!(self.out, ResetColor, Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
@@ -0,0 +1,66 @@
+//! Abstraction over the terminal so the rain animation isn't hard-wired to a single
+//! terminal library. Pick an implementation with the `termion` or `crossterm` Cargo feature.
+
+#[cfg(feature = "crossterm")]
+mod crossterm_backend;
+#[cfg(feature = "termion")]
+mod termion_backend;
+
+#[cfg(feature = "crossterm")]
+pub use crossterm_backend::CrosstermBackend;
+#[cfg(feature = "termion")]
+pub use termion_backend::TermionBackend;
+
+use crate::color::Rgb;
+
+/// A key read from the terminal, independent of the backend that produced it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Char(char),
+    Esc,
+    Other,
+}
+
+/// Everything the rain animation needs from a terminal
+pub trait Backend {
+    /// create a new backend, switching the terminal into raw mode
+    fn new() -> Self
+    where
+        Self: Sized;
+
+    /// current terminal size in columns and rows
+    fn size() -> (u16, u16)
+    where
+        Self: Sized;
+
+    /// hide the text cursor
+    fn hide_cursor(&mut self);
+
+    /// show the text cursor again
+    fn show_cursor(&mut self);
+
+    /// set the foreground color used by subsequently printed characters
+    fn set_fg(&mut self, color: Rgb);
+
+    /// set the background color used by subsequently printed characters and by [`Backend::clear`];
+    /// needed so "blank" cells fade to the theme's tail color instead of the terminal's ambient
+    /// background, which isn't necessarily black
+    fn set_bg(&mut self, color: Rgb);
+
+    /// move the cursor to the given 1-based column/row
+    fn move_to(&mut self, x: u16, y: u16);
+
+    /// clear the entire screen
+    fn clear(&mut self);
+
+    /// print a single character at the current cursor position
+    fn print(&mut self, c: char);
+
+    /// make sure everything written so far actually reaches the terminal
+    fn flush(&mut self);
+
+    /// block until a key is pressed and return it, if it could be decoded
+    fn read_key() -> Option<Key>
+    where
+        Self: Sized;
+}
@@ -0,0 +1,77 @@
+use super::{Backend, Key};
+use crate::color::Rgb;
+use std::io::{stdin, stdout, Stdout, Write};
+use termion::input::TermRead;
+use termion::raw::{IntoRawMode, RawTerminal};
+
+/// Terminal backend built on top of `termion`, for Unix terminals
+pub struct TermionBackend {
+    out: RawTerminal<Stdout>,
+}
+
+impl Backend for TermionBackend {
+    fn new() -> Self {
+        Self {
+            out: stdout().into_raw_mode().unwrap(),
+        }
+    }
+
+    fn size() -> (u16, u16) {
+        termion::terminal_size().unwrap()
+    }
+
+    fn hide_cursor(&mut self) {
+        write!(self.out, "{}", termion::cursor::Hide).unwrap();
+    }
+
+    fn show_cursor(&mut self) {
+        write!(self.out, "{}", termion::cursor::Show).unwrap();
+    }
+
+    fn set_fg(&mut self, color: Rgb) {
+        write!(self.out, "{}", termion::color::Fg(termion::color::Rgb(color.0, color.1, color.2))).unwrap();
+    }
+
+    fn set_bg(&mut self, color: Rgb) {
+        write!(self.out, "{}", termion::color::Bg(termion::color::Rgb(color.0, color.1, color.2))).unwrap();
+    }
+
+    fn move_to(&mut self, x: u16, y: u16) {
+        write!(self.out, "{}", termion::cursor::Goto(x, y)).unwrap();
+    }
+
+    fn clear(&mut self) {
+        write!(self.out, "{}", termion::clear::All).unwrap();
+    }
+
+    fn print(&mut self, c: char) {
+        write!(self.out, "{c}").unwrap();
+    }
+
+    fn flush(&mut self) {
+        self.out.flush().unwrap();
+    }
+
+    fn read_key() -> Option<Key> {
+        match stdin().keys().next()?.ok()? {
+            termion::event::Key::Char(c) => Some(Key::Char(c)),
+            termion::event::Key::Esc => Some(Key::Esc),
+            _ => Some(Key::Other),
+        }
+    }
+}
+
+/// reset the terminal back to normal once the backend is dropped
+impl Drop for TermionBackend {
+    fn drop(&mut self) {
+        let _ = write!(
+            self.out,
+            "{}{}{}{}",
+            termion::style::Reset,
+            termion::clear::All,
+            termion::cursor::Goto(1, 1),
+            termion::cursor::Show
+        );
+        let _ = self.out.flush();
+    }
+}
@@ -0,0 +1,59 @@
+use rand::prelude::ThreadRng;
+use rand::Rng;
+
+/// half-width katakana occupy `U+FF66..=U+FF9D` and render as a single terminal column,
+/// which is what gives the classic Matrix rain its look
+const HALFWIDTH_KATAKANA: std::ops::RangeInclusive<u32> = 0xFF66..=0xFF9D;
+
+const ALPHA_NUM: &[char] = &[
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+    'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '$', '+', '-', '*', '/', '=', '%', '"', '\'', '#',
+    '&', '_', '(', ')', ',', '.', ';', ':', '?', '!', '\\', '|', '~', '<', '>', '[', ']', '{', '}',
+];
+
+/// which characters a [`crate::droplet::Droplet`] is drawn from
+#[derive(Default)]
+pub enum CharSet {
+    /// plain uppercase Latin letters
+    Latin,
+    /// half-width katakana, the classic Matrix rain look
+    #[default]
+    Katakana,
+    /// digits, uppercase Latin letters and a handful of symbols
+    AlphaNum,
+    /// a user-supplied set of characters; must not be empty, or [`CharSet::sample`] panics.
+    /// nothing in this crate's `main` builds one since there's no data to supply it from a
+    /// keypress, but it stays available for embedders who build their own `main`
+    #[allow(dead_code)]
+    Custom(Vec<char>),
+}
+
+impl CharSet {
+    /// cycle to the next built-in preset, wrapping back to [`CharSet::Latin`]; used by the
+    /// interactive charset-switch key. `Custom` has no preset data of its own to cycle to, so it
+    /// falls back to `Latin` the same as wrapping past `AlphaNum`
+    #[must_use]
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Latin => Self::Katakana,
+            Self::Katakana => Self::AlphaNum,
+            Self::AlphaNum | Self::Custom(_) => Self::Latin,
+        }
+    }
+
+    /// draw a random character from this set
+    pub fn sample(&self, rng: &mut ThreadRng) -> char {
+        match self {
+            Self::Latin => rng.gen_range('A'..='Z'),
+            Self::Katakana => {
+                let code_point = rng.gen_range(HALFWIDTH_KATAKANA);
+                char::from_u32(code_point).expect("half-width katakana range only contains valid code points")
+            }
+            Self::AlphaNum => ALPHA_NUM[rng.gen_range(0..ALPHA_NUM.len())],
+            Self::Custom(chars) => {
+                assert!(!chars.is_empty(), "CharSet::Custom must contain at least one character");
+                chars[rng.gen_range(0..chars.len())]
+            }
+        }
+    }
+}
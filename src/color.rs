@@ -0,0 +1,5 @@
+//! Color handling that's independent of any particular terminal backend
+
+/// a 24-bit RGB color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
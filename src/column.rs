@@ -0,0 +1,36 @@
+use crate::symbol::Symbol;
+
+/// a single column of symbols
+#[derive(Clone)]
+pub struct Column {
+    pub symbols: Vec<Symbol>,
+}
+
+impl Column {
+    /// create a new column with a given height
+    pub fn new(height: usize) -> Self {
+        Self {
+            symbols: vec![Symbol::default(); height],
+        }
+    }
+
+    /// reduce the brightness of the entire column
+    pub fn darken(&mut self) {
+        self.symbols.iter_mut().for_each(Symbol::darken);
+    }
+
+    pub fn set(&mut self, row: usize, char: char) {
+        self.symbols[row].set(char);
+    }
+
+    /// mark this row as the right half of a double-width glyph drawn in the column to the left,
+    /// so it doesn't get its own glyph and the grid stays aligned
+    pub fn set_continuation(&mut self, row: usize) {
+        self.symbols[row].set_continuation();
+    }
+
+    /// grow or shrink this column to a new height, preserving existing rows
+    pub fn resize(&mut self, height: usize) {
+        self.symbols.resize(height, Symbol::default());
+    }
+}
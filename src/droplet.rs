@@ -0,0 +1,35 @@
+use rand::prelude::ThreadRng;
+use rand::{thread_rng, Rng};
+
+/// Current position of a _falling symbol_
+pub struct Droplet {
+    /// For the start of the animation we want to be able to place the symbol _above_ the screen,
+    /// that's we need negative row values as well.
+    pub row: isize,
+    pub col: usize,
+}
+
+impl Droplet {
+    /// create a new Droplet at a random location somewhere above the actual screen
+    pub fn new_random(rng: &mut ThreadRng, width: usize, height: usize) -> Self {
+        // the height of the terminal is expected lie within a sane range of this type
+        #[allow(clippy::cast_possible_wrap)]
+        Self {
+            row: -(rng.gen_range(0..height) as isize),
+            col: rng.gen_range(0..width),
+        }
+    }
+
+    /// move the droplet down by one row
+    /// if it hits the bottom row, move it back up to a random column
+    pub fn update(&mut self, width: usize, height: usize) {
+        self.row += 1;
+        // the height of the terminal is expected lie within a sane range of this type
+        #[allow(clippy::cast_possible_wrap)]
+        if self.row >= height as isize {
+            let mut rng = thread_rng();
+            self.col = rng.gen_range(0..width);
+            self.row = 0;
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use rand::thread_rng;
+use std::iter::repeat_n;
+use unicode_width::UnicodeWidthChar;
+
+use crate::backend::Backend;
+use crate::charset::CharSet;
+use crate::color::Rgb;
+use crate::column::Column;
+use crate::droplet::Droplet;
+use crate::theme::Theme;
+
+/// The entire screen filled with colored symbols
+pub struct Screen {
+    pub width: usize,
+    pub height: usize,
+    pub columns: Vec<Column>,
+    /// what has actually been written to the terminal as of the last [`Screen::print`] call,
+    /// kept around so only cells that changed since then need to be redrawn
+    drawn: Vec<Column>,
+    pub droplets: Vec<Droplet>,
+    charset: CharSet,
+    theme: Theme,
+}
+
+impl Screen {
+    /// create a new empty screen with the given dimensions, sampling new symbols from `charset`
+    /// and coloring them according to `theme`
+    pub fn new(width: usize, height: usize, charset: CharSet, theme: Theme) -> Self {
+        let mut rng = thread_rng();
+        Self {
+            width,
+            height,
+            columns: repeat_n(Column::new(height), width).collect(),
+            drawn: repeat_n(Column::new(height), width).collect(),
+            droplets: (0..width).map(|_| Droplet::new_random(&mut rng, width, height)).collect(),
+            charset,
+            theme,
+        }
+    }
+
+    /// the color blank cells fade to under the current theme; the caller is expected to paint
+    /// the terminal's background with this before the first [`Screen::print`] and again after
+    /// every [`Screen::resize`], since resizing implies a fresh [`Backend::clear`]
+    pub fn background_color(&self) -> Rgb {
+        self.theme.background()
+    }
+
+    /// switch to a new color theme; since every on-screen cell's color depends on it, this
+    /// forces a full repaint on the next [`Screen::print`] the same way [`Screen::resize`] does.
+    /// the caller is expected to repaint the terminal's background with [`Screen::background_color`]
+    /// afterwards, same as after a resize
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        self.drawn = repeat_n(Column::new(self.height), self.width).collect();
+    }
+
+    /// print only the cells that changed since the last call to the terminal, then remember
+    /// what's now on screen so the next call can diff against it
+    pub fn print(&mut self, backend: &mut impl Backend) {
+        // where the terminal's cursor will be if we don't move it explicitly, and which color
+        // it'll use if we don't set it explicitly; `None` means "unknown", forcing the first move/color
+        let mut cursor_at: Option<(usize, usize)> = None;
+        let mut last_fg: Option<Rgb> = None;
+
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let symbol = self.columns[col].symbols[row];
+                // the right half of a wide glyph from the previous column; never drawn on its own
+                if symbol.continuation {
+                    continue;
+                }
+
+                let previous = self.drawn[col].symbols[row];
+                if symbol.char == previous.char && symbol.brightness == previous.brightness {
+                    continue;
+                }
+
+                if cursor_at != Some((col, row)) {
+                    // the screen is expected to stay within a sane range of this type
+                    #[allow(clippy::cast_possible_truncation)]
+                    backend.move_to((col + 1) as u16, (row + 1) as u16);
+                }
+
+                let fg = self.theme.color(symbol.brightness, col);
+                if last_fg != Some(fg) {
+                    backend.set_fg(fg);
+                    last_fg = Some(fg);
+                }
+                backend.print(symbol.char);
+
+                // a wide glyph advances the terminal's cursor by two columns, not one
+                let width = symbol.char.width().unwrap_or(1);
+                cursor_at = Some((col + width, row));
+            }
+        }
+
+        self.drawn.clone_from(&self.columns);
+    }
+
+    /// make all droplets fall down by one row
+    pub fn update_droplets(&mut self) {
+        let mut rng = thread_rng();
+        // droplets aren't ordered by column, so two of them can target the same cell within this
+        // one tick (e.g. a wide glyph's continuation column coincides with its neighbour's own
+        // row); track which cells were already written this tick so the first write wins instead
+        // of later droplets silently clobbering what an earlier one just drew
+        let mut written = std::collections::HashSet::new();
+        for droplet in &mut self.droplets {
+            droplet.update(self.width, self.height);
+            if let Ok(row) = droplet.row.try_into() {
+                if !written.insert((droplet.col, row)) {
+                    continue;
+                }
+
+                let char = self.charset.sample(&mut rng);
+                self.columns[droplet.col].set(row, char);
+
+                // a wide glyph occupies two terminal columns; reserve the neighbour so the grid
+                // stays aligned, unless that neighbour was already written this tick
+                if char.width().unwrap_or(1) > 1
+                    && droplet.col + 1 < self.width
+                    && written.insert((droplet.col + 1, row))
+                {
+                    self.columns[droplet.col + 1].set_continuation(row);
+                }
+            }
+        }
+    }
+
+    /// reduce the brightness of all symbols in this screen
+    pub fn darken(&mut self) {
+        self.columns.iter_mut().for_each(Column::darken);
+    }
+
+    /// adapt to a new terminal size, growing or shrinking in place
+    ///
+    /// existing columns keep their brightness, new columns start out blank, droplets that fell
+    /// outside the new width are dropped, and the "last drawn" state is reset to blank since the
+    /// caller is expected to clear the physical terminal on resize
+    pub fn resize(&mut self, width: usize, height: usize) {
+        let old_width = self.width;
+
+        self.columns.resize_with(width, || Column::new(height));
+        for column in &mut self.columns {
+            column.resize(height);
+        }
+        self.drawn = repeat_n(Column::new(height), width).collect();
+
+        self.droplets.retain(|droplet| droplet.col < width);
+        if width > old_width {
+            let mut rng = thread_rng();
+            self.droplets.extend((old_width..width).map(|_| Droplet::new_random(&mut rng, width, height)));
+        }
+
+        self.width = width;
+        self.height = height;
+    }
+
+    /// switch to the next built-in charset preset; only future droplets are affected, symbols
+    /// already on screen keep whatever character they were sampled with
+    pub fn cycle_charset(&mut self) {
+        self.charset = self.charset.next();
+    }
+
+    /// add one more droplet at a random position above the screen
+    pub fn add_droplet(&mut self) {
+        let mut rng = thread_rng();
+        self.droplets.push(Droplet::new_random(&mut rng, self.width, self.height));
+    }
+
+    /// remove the most recently added droplet, if there is one
+    pub fn remove_droplet(&mut self) {
+        self.droplets.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a wide glyph's continuation cell must not be clobbered within the same tick by a
+    /// neighbouring droplet that happens to land on the same row later in the droplet vector
+    #[test]
+    fn wide_custom_charset_does_not_clobber_same_tick_writes() {
+        // '永' is a full-width CJK character
+        let mut screen = Screen::new(3, 1, CharSet::Custom(vec!['永']), Theme::default());
+        // force all three droplets to land on row 0 this tick, in column order
+        screen.droplets = vec![
+            Droplet { row: -1, col: 0 },
+            Droplet { row: -1, col: 1 },
+            Droplet { row: -1, col: 2 },
+        ];
+
+        screen.update_droplets();
+
+        // column 0 drew its own wide glyph and claimed column 1 as its continuation
+        assert_eq!(screen.columns[0].symbols[0].char, '永');
+        assert!(!screen.columns[0].symbols[0].continuation);
+        assert!(screen.columns[1].symbols[0].continuation);
+        // column 1's own droplet lost the race for its cell and did not overwrite the
+        // continuation column 0 just claimed
+        assert_eq!(screen.columns[1].symbols[0].char, ' ');
+        // column 2 wasn't contended for, so its droplet drew its own glyph normally
+        assert_eq!(screen.columns[2].symbols[0].char, '永');
+        assert!(!screen.columns[2].symbols[0].continuation);
+    }
+
+    /// a continuation marking from an earlier tick must be cleared once that cell is
+    /// overwritten by a real glyph on a later tick
+    #[test]
+    fn narrow_overwrite_clears_stale_continuation() {
+        let mut column = Column::new(1);
+        column.set_continuation(0);
+        assert!(column.symbols[0].continuation);
+
+        column.set(0, 'a');
+        assert!(!column.symbols[0].continuation);
+        assert_eq!(column.symbols[0].char, 'a');
+    }
+}
@@ -0,0 +1,43 @@
+/// A single character on the screen with its current brightness
+#[derive(Clone, Copy)]
+pub struct Symbol {
+    pub char: char,
+    pub brightness: u8,
+    /// true if this cell is the right half of a double-width glyph drawn in the column to its
+    /// left; such a cell must never be drawn on its own, only skipped over when rendering.
+    ///
+    /// this is tracked separately from `char` rather than reusing a sentinel value, since `char`
+    /// is also the type `CharSet::Custom` exposes to callers and a sentinel could collide with a
+    /// legitimately sampled character
+    pub continuation: bool,
+}
+
+/// Start with a black space by default
+impl Default for Symbol {
+    fn default() -> Self {
+        Self { char: ' ', brightness: 0, continuation: false }
+    }
+}
+
+impl Symbol {
+    /// reduce the brightness of the symbol by a certain amount and make sure the value doesn't underrun
+    pub fn darken(&mut self) {
+        self.brightness = self.brightness.saturating_sub(10);
+    }
+
+    /// replace the character for this symbol and bring it to full brightness; clears a stale
+    /// continuation marking, since this cell now holds a real glyph of its own
+    pub fn set(&mut self, char: char) {
+        self.char = char;
+        self.brightness = 255;
+        self.continuation = false;
+    }
+
+    /// mark this symbol as the right half of a double-width glyph drawn in the column to the
+    /// left, so it doesn't get its own glyph and the grid stays aligned
+    pub fn set_continuation(&mut self) {
+        self.char = ' ';
+        self.brightness = 255;
+        self.continuation = true;
+    }
+}
@@ -0,0 +1,107 @@
+use crate::color::Rgb;
+
+/// describes how a symbol's brightness (and, for [`Theme::Rainbow`], its column) maps to a
+/// displayed color
+#[derive(Clone, Copy)]
+pub enum Theme {
+    /// fade from `tail` (brightness 0) towards `head` (brightness 255), with a separate power
+    /// curve per color channel so e.g. the green channel can stay lit longer than red and blue
+    Gradient {
+        head: Rgb,
+        tail: Rgb,
+        exponents: (f32, f32, f32),
+    },
+    /// hue cycles across the columns, while brightness controls how lit up a cell is
+    Rainbow,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::classic_green()
+    }
+}
+
+impl Theme {
+    /// the original green Matrix gradient: white sparks fading through green to black
+    pub fn classic_green() -> Self {
+        Self::Gradient { head: Rgb(255, 255, 255), tail: Rgb(0, 0, 0), exponents: (7.0, 1.0, 4.0) }
+    }
+
+    /// amber monochrome, reminiscent of old phosphor terminals
+    pub fn amber() -> Self {
+        Self::Gradient { head: Rgb(255, 255, 220), tail: Rgb(0, 0, 0), exponents: (1.0, 2.0, 6.0) }
+    }
+
+    /// cool blue gradient
+    pub fn ice_blue() -> Self {
+        Self::Gradient { head: Rgb(230, 255, 255), tail: Rgb(0, 0, 0), exponents: (6.0, 2.0, 1.0) }
+    }
+
+    /// hue cycles across the columns instead of using one fixed color
+    pub fn rainbow() -> Self {
+        Self::Rainbow
+    }
+
+    /// every built-in preset, in the order the interactive theme-switch key cycles through them
+    pub const PRESETS: [fn() -> Theme; 4] = [Self::classic_green, Self::amber, Self::ice_blue, Self::rainbow];
+
+    /// the color a blank (brightness 0) cell renders as, used to paint the terminal's background
+    /// so the "fade to black" effect doesn't depend on the terminal's ambient background color
+    pub fn background(&self) -> Rgb {
+        self.color(0, 0)
+    }
+
+    /// map a symbol's brightness (and column, for themes that use it) to an RGB color
+    pub fn color(&self, brightness: u8, column: usize) -> Rgb {
+        let v = f32::from(brightness) / 255.0;
+        match *self {
+            Self::Gradient { head, tail, exponents: (exp_r, exp_g, exp_b) } => Rgb(
+                mix(tail.0, head.0, v.powf(exp_r)),
+                mix(tail.1, head.1, v.powf(exp_g)),
+                mix(tail.2, head.2, v.powf(exp_b)),
+            ),
+            Self::Rainbow => {
+                // one full hue rotation every 36 columns
+                #[allow(clippy::cast_precision_loss)]
+                let hue = (column % 36) as f32 / 36.0 * 360.0;
+                hsv_to_rgb(hue, 1.0, v)
+            }
+        }
+    }
+}
+
+/// linearly blend two channel values, with `t` in `0.0..=1.0`
+fn mix(from: u8, to: u8, t: f32) -> u8 {
+    let from = f32::from(from);
+    let to = f32::from(to);
+    // `from`, `to` are in 0.0..=255.0 and `t` is in 0.0..=1.0, so the result can't leave that range
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    {
+        (from + (to - from) * t).round() as u8
+    }
+}
+
+/// convert a hue in degrees plus saturation/value in `0.0..=1.0` to an RGB color
+#[allow(clippy::many_single_char_names)]
+fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> Rgb {
+    let chroma = value * saturation;
+    let h_prime = hue / 60.0;
+    let mid = chroma * (1.0 - (h_prime.rem_euclid(2.0) - 1.0).abs());
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (red, green, blue) = match h_prime as u32 {
+        0 => (chroma, mid, 0.0),
+        1 => (mid, chroma, 0.0),
+        2 => (0.0, chroma, mid),
+        3 => (0.0, mid, chroma),
+        4 => (mid, 0.0, chroma),
+        _ => (chroma, 0.0, mid),
+    };
+    let offset = value - chroma;
+    // red/green/blue + offset stay within 0.0..=1.0 for hue in 0.0..=360.0, saturation/value in 0.0..=1.0
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Rgb(
+        ((red + offset) * 255.0).round() as u8,
+        ((green + offset) * 255.0).round() as u8,
+        ((blue + offset) * 255.0).round() as u8,
+    )
+}